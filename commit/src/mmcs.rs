@@ -15,11 +15,11 @@ use serde::Serialize;
 /// with the largest height. For matrices with smaller heights, some bits of the row index are
 /// removed (from the least-significant side) to get the effective row index. These semantics are
 /// useful in the FRI protocol. See the documentation for `open_batch` for more details.
-pub trait Mmcs<T: Send + Sync>: Clone {
+pub trait Mmcs<T: Send + Sync>: Clone + Sync {
     type ProverData<M>;
-    type Commitment: Clone + Serialize + DeserializeOwned;
-    type Proof: Clone + Serialize + DeserializeOwned;
-    type Error: Debug;
+    type Commitment: Clone + Serialize + DeserializeOwned + Send + Sync;
+    type Proof: Clone + Serialize + DeserializeOwned + Send + Sync;
+    type Error: Debug + Send + Sync;
 
     fn commit<M: Matrix<T>>(&self, inputs: Vec<M>) -> (Self::Commitment, Self::ProverData<M>);
 
@@ -6,6 +6,7 @@ use p3_challenger::{CanObserve, CanSample, GrindingChallenger};
 use p3_commit::Mmcs;
 use p3_field::Field;
 use p3_matrix::Dimensions;
+use p3_maybe_rayon::prelude::*;
 
 use crate::{CommitPhaseProofStep, FriConfig, FriGenericConfig, FriProof};
 
@@ -23,13 +24,15 @@ pub fn verify<G, F, M, Challenger>(
     config: &FriConfig<M>,
     proof: &FriProof<F, M, Challenger::Witness, G::InputProof>,
     challenger: &mut Challenger,
-    open_input: impl Fn(usize, &G::InputProof) -> Result<Vec<(usize, F)>, G::InputError>,
+    open_input: impl Fn(usize, &G::InputProof) -> Result<Vec<(usize, F)>, G::InputError> + Sync,
 ) -> Result<(), FriError<M::Error, G::InputError>>
 where
     F: Field,
     M: Mmcs<F>,
     Challenger: GrindingChallenger + CanObserve<M::Commitment> + CanSample<F>,
-    G: FriGenericConfig<F>,
+    G: FriGenericConfig<F> + Sync,
+    G::InputProof: Sync,
+    G::InputError: Send,
 {
     let betas: Vec<F> = proof
         .commit_phase_commits
@@ -51,34 +54,43 @@ where
 
     let log_max_height = proof.commit_phase_commits.len() + config.log_blowup;
 
-    for qp in &proof.query_proofs {
-        let index = challenger.sample_bits(log_max_height + g.extra_query_index_bits());
-        let ro = open_input(index, &qp.input_proof).map_err(FriError::InputError)?;
-
-        debug_assert!(
-            ro.iter().tuple_windows().all(|((l, _), (r, _))| l > r),
-            "reduced openings sorted by height descending"
-        );
-
-        let folded_eval = verify_query(
-            g,
-            config,
-            index >> g.extra_query_index_bits(),
-            izip!(
-                &betas,
-                &proof.commit_phase_commits,
-                &qp.commit_phase_openings
-            ),
-            ro,
-            log_max_height,
-        )?;
-
-        if folded_eval != proof.final_poly {
-            return Err(FriError::FinalPolyMismatch);
-        }
-    }
+    // The query indices are the only part of the query phase which touches the transcript, so we
+    // sample them all up front; the queries themselves are then independent of each other.
+    let indices: Vec<usize> = (0..config.num_queries)
+        .map(|_| challenger.sample_bits(log_max_height + g.extra_query_index_bits()))
+        .collect();
 
-    Ok(())
+    proof
+        .query_proofs
+        .par_iter()
+        .zip(indices)
+        .try_for_each(|(qp, index)| {
+            let ro = open_input(index, &qp.input_proof).map_err(FriError::InputError)?;
+
+            debug_assert!(
+                ro.iter().tuple_windows().all(|((l, _), (r, _))| l > r),
+                "reduced openings sorted by height descending"
+            );
+
+            let folded_eval = verify_query(
+                g,
+                config,
+                index >> g.extra_query_index_bits(),
+                izip!(
+                    &betas,
+                    &proof.commit_phase_commits,
+                    &qp.commit_phase_openings
+                ),
+                ro,
+                log_max_height,
+            )?;
+
+            if folded_eval != proof.final_poly {
+                return Err(FriError::FinalPolyMismatch);
+            }
+
+            Ok(())
+        })
 }
 
 type CommitStep<'a, F, M> = (
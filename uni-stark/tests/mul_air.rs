@@ -11,7 +11,9 @@ use p3_dft::Radix2DitParallel;
 use p3_field::extension::BinomialExtensionField;
 use p3_field::{AbstractField, Field};
 use p3_fri::{FriConfig, TwoAdicFriPcs};
+use p3_goldilocks::{DiffusionMatrixGoldilocks, Goldilocks};
 use p3_keccak::Keccak256Hash;
+use p3_koala_bear::{DiffusionMatrixKoalaBear, KoalaBear};
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
 use p3_merkle_tree::FieldMerkleTreeMmcs;
@@ -273,6 +275,152 @@ fn prove_bb_twoadic_deg5() -> Result<(), VerificationError> {
     do_test_bb_twoadic(2, 5, 6)
 }
 
+fn do_test_gl_twoadic(
+    log_blowup: usize,
+    degree: u64,
+    log_n: usize,
+) -> Result<(), VerificationError> {
+    type Val = Goldilocks;
+    type Challenge = BinomialExtensionField<Val, 2>;
+
+    type Perm = Poseidon2<Val, Poseidon2ExternalMatrixGeneral, DiffusionMatrixGoldilocks, 8, 7>;
+    let perm = Perm::new_from_rng_128(
+        Poseidon2ExternalMatrixGeneral,
+        DiffusionMatrixGoldilocks,
+        &mut thread_rng(),
+    );
+
+    type MyHash = PaddingFreeSponge<Perm, 8, 4, 4>;
+    let hash = MyHash::new(perm.clone());
+
+    type MyCompress = TruncatedPermutation<Perm, 2, 4, 8>;
+    let compress = MyCompress::new(perm.clone());
+
+    type ValMmcs = FieldMerkleTreeMmcs<
+        <Val as Field>::Packing,
+        <Val as Field>::Packing,
+        MyHash,
+        MyCompress,
+        4,
+    >;
+    let val_mmcs = ValMmcs::new(hash, compress);
+
+    type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+
+    type Dft = Radix2DitParallel;
+    let dft = Dft {};
+
+    type Challenger = DuplexChallenger<Val, Perm, 8, 4>;
+
+    let fri_config = FriConfig {
+        log_blowup,
+        num_queries: 40,
+        proof_of_work_bits: 8,
+        mmcs: challenge_mmcs,
+    };
+    type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+    let pcs = Pcs::new(dft, val_mmcs, fri_config);
+
+    type MyConfig = StarkConfig<Pcs, Challenge, Challenger>;
+    let config = MyConfig::new(pcs);
+
+    let air = MulAir {
+        degree,
+        ..Default::default()
+    };
+
+    do_test(config, air, 1 << log_n, Challenger::new(perm))
+}
+
+#[test]
+fn prove_gl_twoadic_deg2() -> Result<(), VerificationError> {
+    do_test_gl_twoadic(1, 2, 7)
+}
+
+#[test]
+fn prove_gl_twoadic_deg3() -> Result<(), VerificationError> {
+    do_test_gl_twoadic(1, 3, 7)
+}
+
+#[test]
+fn prove_gl_twoadic_deg4() -> Result<(), VerificationError> {
+    do_test_gl_twoadic(2, 4, 6)
+}
+
+fn do_test_kb_twoadic(
+    log_blowup: usize,
+    degree: u64,
+    log_n: usize,
+) -> Result<(), VerificationError> {
+    type Val = KoalaBear;
+    type Challenge = BinomialExtensionField<Val, 4>;
+
+    type Perm = Poseidon2<Val, Poseidon2ExternalMatrixGeneral, DiffusionMatrixKoalaBear, 16, 3>;
+    let perm = Perm::new_from_rng_128(
+        Poseidon2ExternalMatrixGeneral,
+        DiffusionMatrixKoalaBear,
+        &mut thread_rng(),
+    );
+
+    type MyHash = PaddingFreeSponge<Perm, 16, 8, 8>;
+    let hash = MyHash::new(perm.clone());
+
+    type MyCompress = TruncatedPermutation<Perm, 2, 8, 16>;
+    let compress = MyCompress::new(perm.clone());
+
+    type ValMmcs = FieldMerkleTreeMmcs<
+        <Val as Field>::Packing,
+        <Val as Field>::Packing,
+        MyHash,
+        MyCompress,
+        8,
+    >;
+    let val_mmcs = ValMmcs::new(hash, compress);
+
+    type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+
+    type Dft = Radix2DitParallel;
+    let dft = Dft {};
+
+    type Challenger = DuplexChallenger<Val, Perm, 16, 8>;
+
+    let fri_config = FriConfig {
+        log_blowup,
+        num_queries: 40,
+        proof_of_work_bits: 8,
+        mmcs: challenge_mmcs,
+    };
+    type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+    let pcs = Pcs::new(dft, val_mmcs, fri_config);
+
+    type MyConfig = StarkConfig<Pcs, Challenge, Challenger>;
+    let config = MyConfig::new(pcs);
+
+    let air = MulAir {
+        degree,
+        ..Default::default()
+    };
+
+    do_test(config, air, 1 << log_n, Challenger::new(perm))
+}
+
+#[test]
+fn prove_kb_twoadic_deg2() -> Result<(), VerificationError> {
+    do_test_kb_twoadic(1, 2, 7)
+}
+
+#[test]
+fn prove_kb_twoadic_deg3() -> Result<(), VerificationError> {
+    do_test_kb_twoadic(1, 3, 7)
+}
+
+#[test]
+fn prove_kb_twoadic_deg4() -> Result<(), VerificationError> {
+    do_test_kb_twoadic(2, 4, 6)
+}
+
 fn do_test_m31_circle(
     log_blowup: usize,
     degree: u64,